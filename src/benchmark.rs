@@ -0,0 +1,176 @@
+use crate::word_store::WordStore;
+use crate::{best_guesses, pattern, AggregateWordResult, GuessedLetter, GuessedWord};
+use rayon::prelude::*;
+use std::fmt;
+
+const MAX_GUESSES: usize = 6;
+
+#[derive(Debug)]
+pub(crate) struct BenchmarkReport {
+    total: usize,
+    failures: usize,
+    average_guesses: Option<f64>,
+    worst_case: usize,
+    win_rate_under_6: Option<f64>,
+}
+
+impl fmt::Display for BenchmarkReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Benchmarked {} word(s):", self.total)?;
+        match self.average_guesses {
+            Some(average_guesses) => writeln!(f, "  average guesses: {:.2}", average_guesses)?,
+            None => writeln!(f, "  average guesses: n/a")?,
+        }
+        writeln!(f, "  worst case: {}", self.worst_case)?;
+        match self.win_rate_under_6 {
+            Some(win_rate_under_6) => writeln!(
+                f,
+                "  win rate within {} guesses: {:.1}%",
+                MAX_GUESSES,
+                win_rate_under_6 * 100.0
+            )?,
+            None => writeln!(f, "  win rate within {} guesses: n/a", MAX_GUESSES)?,
+        }
+        write!(f, "  failures: {}", self.failures)
+    }
+}
+
+/// Simulates the full solve loop for every word in `word_pool` as the
+/// secret, using the entropy recommender to pick each follow-up guess, and
+/// reports how many guesses it took. Secrets are evaluated in parallel since
+/// a full solve is run for each one.
+pub(crate) fn run_benchmark(word_pool: &[&str], store: &WordStore, length: usize) -> BenchmarkReport {
+    let opener = pick_opener(word_pool);
+    let results: Vec<Option<usize>> = word_pool
+        .par_iter()
+        .map(|secret| simulate_solve(secret, word_pool, store, opener, length))
+        .collect();
+
+    let total = results.len();
+    let failures = results.iter().filter(|r| r.is_none()).count();
+    let solved: Vec<usize> = results.into_iter().flatten().collect();
+    let average_guesses = if solved.is_empty() {
+        None
+    } else {
+        Some(solved.iter().sum::<usize>() as f64 / solved.len() as f64)
+    };
+    let worst_case = solved.iter().copied().max().unwrap_or(0);
+    let wins_within_max = solved.iter().filter(|&&guesses| guesses <= MAX_GUESSES).count();
+    let win_rate_under_6 = if total == 0 {
+        None
+    } else {
+        Some(wins_within_max as f64 / total as f64)
+    };
+
+    BenchmarkReport {
+        total,
+        failures,
+        average_guesses,
+        worst_case,
+        win_rate_under_6,
+    }
+}
+
+/// Plays out a full solve for `secret`, returning the number of guesses it
+/// took, or `None` if it wasn't solved within `MAX_GUESSES`.
+fn simulate_solve(
+    secret: &str,
+    word_pool: &[&str],
+    store: &WordStore,
+    opener: &str,
+    length: usize,
+) -> Option<usize> {
+    let mut guessed_words: Vec<GuessedWord> = Vec::new();
+    let mut guess = opener.to_string();
+
+    for attempt in 1..=MAX_GUESSES {
+        let letters = pattern(&guess, secret)
+            .into_iter()
+            .zip(guess.chars())
+            .map(|(result, letter)| GuessedLetter { letter, result })
+            .collect();
+        guessed_words.push(GuessedWord { letters });
+
+        if guess == secret {
+            return Some(attempt);
+        }
+
+        let agg = AggregateWordResult::from(&guessed_words, length);
+        let candidates: Vec<String> = agg.matching_words(store).collect();
+        if candidates.is_empty() {
+            return None;
+        }
+        let candidate_refs: Vec<&str> = candidates.iter().map(String::as_str).collect();
+
+        guess = best_guesses(&candidate_refs, word_pool, 1)
+            .into_iter()
+            .next()?
+            .0;
+    }
+
+    None
+}
+
+fn pick_opener<'a>(word_pool: &[&'a str]) -> &'a str {
+    word_pool
+        .iter()
+        .find(|&&word| word == "arose")
+        .copied()
+        .or_else(|| word_pool.first().copied())
+        .unwrap_or("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simulate_solve_finds_the_opener_in_one_guess() {
+        let pool = vec!["arose", "racer", "cargo"];
+        let store = WordStore::build(&pool);
+        assert_eq!(
+            simulate_solve("arose", &pool, &store, "arose", 5),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn run_benchmark_reports_every_word() {
+        let pool = vec!["arose", "racer", "cargo"];
+        let store = WordStore::build(&pool);
+        let report = run_benchmark(&pool, &store, 5);
+        assert_eq!(report.total, 3);
+    }
+
+    #[test]
+    fn run_benchmark_reports_na_instead_of_nan_for_an_empty_pool() {
+        let pool: Vec<&str> = Vec::new();
+        let store = WordStore::build(&pool);
+        let report = run_benchmark(&pool, &store, 5);
+        assert_eq!(report.average_guesses, None);
+        assert_eq!(report.win_rate_under_6, None);
+        assert!(report.to_string().contains("average guesses: n/a"));
+        assert!(report.to_string().contains("win rate within 6 guesses: n/a"));
+    }
+
+    // Regression guard: this is exactly the check that should have failed
+    // before the `best_guesses` tie-break fix, when the solver kept
+    // re-recommending the opener forever once only one candidate remained
+    // and the builtin list scored a 1.4% win rate.
+    #[test]
+    fn run_benchmark_solves_most_of_a_builtin_sample() {
+        let pool: Vec<&str> = crate::wordle_words::word_list()
+            .into_iter()
+            .filter(|word| word.chars().count() == 5)
+            .take(100)
+            .collect();
+        let store = WordStore::build(&pool);
+        let report = run_benchmark(&pool, &store, 5);
+        let win_rate_under_6 = report.win_rate_under_6.expect("non-empty pool always has a win rate");
+        assert!(
+            win_rate_under_6 > 0.9,
+            "win rate regressed to {:.1}%",
+            win_rate_under_6 * 100.0
+        );
+    }
+}
@@ -1,12 +1,30 @@
 use crate::wordle_words::word_list;
-use std::collections::HashSet;
+use colored::Colorize;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::io;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
 
+mod benchmark;
+mod word_store;
 mod wordle_words;
 
-#[derive(Debug, Clone)]
-enum GuessedLetterResult {
+use crate::word_store::WordStore;
+use fst::Automaton;
+
+static COLOR_ENABLED: AtomicBool = AtomicBool::new(false);
+
+fn set_color_enabled(enabled: bool) {
+    COLOR_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+fn color_enabled() -> bool {
+    COLOR_ENABLED.load(Ordering::Relaxed)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum GuessedLetterResult {
     NotUsed,
     WrongSpot,
     CorrectSpot,
@@ -23,20 +41,29 @@ impl fmt::Display for GuessedLetterResult {
 }
 
 #[derive(Debug, Clone)]
-struct GuessedLetter {
-    letter: char,
-    result: GuessedLetterResult,
+pub(crate) struct GuessedLetter {
+    pub(crate) letter: char,
+    pub(crate) result: GuessedLetterResult,
 }
 
 impl fmt::Display for GuessedLetter {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "[{} - {}] ", self.letter.to_uppercase(), self.result)
+        if !color_enabled() {
+            return write!(f, "[{} - {}] ", self.letter.to_uppercase(), self.result);
+        }
+        let tile = format!(" {} ", self.letter.to_ascii_uppercase());
+        let tile = match self.result {
+            GuessedLetterResult::CorrectSpot => tile.black().on_green(),
+            GuessedLetterResult::WrongSpot => tile.black().on_yellow(),
+            GuessedLetterResult::NotUsed => tile.white().on_bright_black(),
+        };
+        write!(f, "{} ", tile)
     }
 }
 
 #[derive(Debug)]
-struct GuessedWord {
-    letters: Vec<GuessedLetter>,
+pub(crate) struct GuessedWord {
+    pub(crate) letters: Vec<GuessedLetter>,
 }
 
 impl fmt::Display for GuessedWord {
@@ -55,19 +82,23 @@ struct AggregateLetterResult {
 }
 
 #[derive(Debug)]
-struct AggregateWordResult {
-    not_used: HashSet<char>,
-    used_somewhere: HashSet<char>,
+pub(crate) struct AggregateWordResult {
+    // lower bound on how many times a letter must appear in the solution,
+    // taken as the max over all guesses of that letter's green+yellow count
+    min_count: HashMap<char, u8>,
+    // upper bound on how many times a letter can appear in the solution,
+    // set whenever a guess contains a black occurrence of that letter
+    max_count: HashMap<char, u8>,
     aggregate_letter_results: Vec<AggregateLetterResult>,
 }
 
 impl AggregateWordResult {
-    fn from(guessed_words: &[GuessedWord]) -> AggregateWordResult {
-        let mut not_used: HashSet<char> = HashSet::new();
-        let mut used_somewhere: HashSet<char> = HashSet::new();
+    pub(crate) fn from(guessed_words: &[GuessedWord], length: usize) -> AggregateWordResult {
+        let mut min_count: HashMap<char, u8> = HashMap::new();
+        let mut max_count: HashMap<char, u8> = HashMap::new();
         let mut letter_index = 0;
         let mut aggregate_letter_results = Vec::new();
-        while letter_index < 5 {
+        while letter_index < length {
             let mut correct_spot: Option<char> = None;
             let mut wrong_spot = HashSet::new();
             for word in guessed_words {
@@ -76,29 +107,50 @@ impl AggregateWordResult {
                     GuessedLetterResult::CorrectSpot => {
                         correct_spot.or(Some(current_letter.letter))
                     }
-                    _ => None,
-                };
-                match current_letter.result {
-                    GuessedLetterResult::WrongSpot => {
-                        wrong_spot.insert(Some(current_letter.letter).unwrap());
-                        used_somewhere.insert(Some(current_letter.letter).unwrap())
-                    }
-                    GuessedLetterResult::NotUsed => {
-                        not_used.insert(Some(current_letter.letter).unwrap())
-                    }
-                    _ => false,
+                    _ => correct_spot,
                 };
+                if let GuessedLetterResult::WrongSpot = current_letter.result {
+                    wrong_spot.insert(current_letter.letter);
+                }
             }
             aggregate_letter_results.push(AggregateLetterResult {
-                // TODO: figure out how to accomplish this without a clone
-                wrong_spot: wrong_spot.clone(),
+                wrong_spot,
                 correct_spot,
             });
             letter_index += 1;
         }
+
+        for word in guessed_words {
+            let mut non_black_counts: HashMap<char, u8> = HashMap::new();
+            let mut black_letters: HashSet<char> = HashSet::new();
+            for letter in &word.letters {
+                match letter.result {
+                    GuessedLetterResult::NotUsed => {
+                        black_letters.insert(letter.letter);
+                    }
+                    _ => {
+                        *non_black_counts.entry(letter.letter).or_insert(0) += 1;
+                    }
+                }
+            }
+            for (chr, count) in &non_black_counts {
+                min_count
+                    .entry(*chr)
+                    .and_modify(|existing| *existing = (*existing).max(*count))
+                    .or_insert(*count);
+            }
+            for chr in black_letters {
+                let count = *non_black_counts.get(&chr).unwrap_or(&0);
+                max_count
+                    .entry(chr)
+                    .and_modify(|existing| *existing = (*existing).min(count))
+                    .or_insert(count);
+            }
+        }
+
         AggregateWordResult {
-            not_used,
-            used_somewhere,
+            min_count,
+            max_count,
             aggregate_letter_results,
         }
     }
@@ -108,7 +160,7 @@ impl AggregateWordResult {
         let aggregate_letter_result = &self.aggregate_letter_results[index];
         successful_match = successful_match
             && match aggregate_letter_result.correct_spot {
-                Some(i) => (i == chr),
+                Some(i) => i == chr,
                 _ => true,
             };
         for letter in &aggregate_letter_result.wrong_spot {
@@ -119,25 +171,184 @@ impl AggregateWordResult {
         successful_match
     }
 
-    fn word_matches(&self, strng: &str) -> bool {
+    // Linear reference implementation kept only so tests can check
+    // `matching_words` (the FST-backed hot path) against a straightforward
+    // scan; nothing outside tests calls this anymore.
+    #[cfg(test)]
+    pub(crate) fn word_matches(&self, strng: &str) -> bool {
         let mut successful_match = true;
-        successful_match = successful_match && set_not_in_str(&self.not_used, strng);
-        successful_match = successful_match && used_at_least_once(&self.used_somewhere, strng);
+        for (&chr, &min) in &self.min_count {
+            successful_match = successful_match && letter_count(strng, chr) >= min;
+        }
+        for (&chr, &max) in &self.max_count {
+            successful_match = successful_match && letter_count(strng, chr) <= max;
+        }
         for (position, letter) in strng.chars().enumerate() {
             successful_match = successful_match && self.letter_matches(position, letter);
         }
         successful_match
     }
+
+    /// Same constraints as `word_matches`, but streamed directly out of an
+    /// FST-backed `store` instead of scanning every candidate word.
+    pub(crate) fn matching_words(&self, store: &WordStore) -> impl Iterator<Item = String> {
+        let constrained_letters: Vec<char> = self
+            .min_count
+            .keys()
+            .chain(self.max_count.keys())
+            .copied()
+            .collect::<HashSet<char>>()
+            .into_iter()
+            .collect();
+        let automaton = ConstraintAutomaton {
+            agg: self,
+            length: self.aggregate_letter_results.len(),
+            constrained_letters,
+        };
+        store.matching(automaton).into_iter()
+    }
+}
+
+/// Tracks, as the FST traversal descends byte by byte, how far into the word
+/// we are and how many occurrences of each letter in `constrained_letters`
+/// we've seen so far, so `min_count`/`max_count` bounds can be checked
+/// without ever materializing the word.
+#[derive(Debug, Clone)]
+struct ConstraintState {
+    position: usize,
+    counts: Vec<u8>,
+    // bytes of the current, not-yet-complete UTF-8 character
+    pending: Vec<u8>,
+    failed: bool,
+}
+
+struct ConstraintAutomaton<'a> {
+    agg: &'a AggregateWordResult,
+    length: usize,
+    constrained_letters: Vec<char>,
+}
+
+/// Number of bytes in the UTF-8 sequence starting with `lead_byte`.
+fn utf8_sequence_len(lead_byte: u8) -> usize {
+    if lead_byte & 0x80 == 0 {
+        1
+    } else if lead_byte & 0xE0 == 0xC0 {
+        2
+    } else if lead_byte & 0xF0 == 0xE0 {
+        3
+    } else {
+        4
+    }
+}
+
+impl<'a> Automaton for ConstraintAutomaton<'a> {
+    type State = ConstraintState;
+
+    fn start(&self) -> ConstraintState {
+        ConstraintState {
+            position: 0,
+            counts: vec![0; self.constrained_letters.len()],
+            pending: Vec::new(),
+            failed: false,
+        }
+    }
+
+    fn is_match(&self, state: &ConstraintState) -> bool {
+        if state.failed || state.position != self.length || !state.pending.is_empty() {
+            return false;
+        }
+        self.constrained_letters
+            .iter()
+            .zip(&state.counts)
+            .all(|(chr, &count)| self.agg.min_count.get(chr).is_none_or(|&min| count >= min))
+    }
+
+    fn can_match(&self, state: &ConstraintState) -> bool {
+        !state.failed
+    }
+
+    // The FST walks one byte at a time, but `length`/`min_count`/`max_count`
+    // are all expressed in Unicode-scalar (char) counts, so multi-byte
+    // characters are buffered in `pending` until a full character is
+    // available and only then checked and counted as a single position.
+    fn accept(&self, state: &ConstraintState, byte: u8) -> ConstraintState {
+        if state.failed {
+            return state.clone();
+        }
+
+        let mut pending = state.pending.clone();
+        pending.push(byte);
+        let expected_len = utf8_sequence_len(pending[0]);
+        if pending.len() < expected_len {
+            return ConstraintState {
+                position: state.position,
+                counts: state.counts.clone(),
+                pending,
+                failed: false,
+            };
+        }
+
+        if state.position >= self.length {
+            return ConstraintState {
+                failed: true,
+                ..state.clone()
+            };
+        }
+        let chr = match std::str::from_utf8(&pending) {
+            Ok(decoded) => decoded.chars().next().expect("non-empty utf8 sequence"),
+            Err(_) => {
+                return ConstraintState {
+                    position: state.position + 1,
+                    counts: state.counts.clone(),
+                    pending: Vec::new(),
+                    failed: true,
+                };
+            }
+        };
+
+        if !self.agg.letter_matches(state.position, chr) {
+            return ConstraintState {
+                position: state.position + 1,
+                counts: state.counts.clone(),
+                pending: Vec::new(),
+                failed: true,
+            };
+        }
+        let mut counts = state.counts.clone();
+        if let Some(i) = self.constrained_letters.iter().position(|&c| c == chr) {
+            counts[i] += 1;
+            if let Some(&max) = self.agg.max_count.get(&chr) {
+                if counts[i] > max {
+                    return ConstraintState {
+                        position: state.position + 1,
+                        counts,
+                        pending: Vec::new(),
+                        failed: true,
+                    };
+                }
+            }
+        }
+        ConstraintState {
+            position: state.position + 1,
+            counts,
+            pending: Vec::new(),
+            failed: false,
+        }
+    }
 }
 
 #[derive(Debug)]
 struct Guesses {
     vec: Vec<GuessedWord>,
+    length: usize,
 }
 
 impl Guesses {
-    fn new() -> Guesses {
-        Guesses { vec: Vec::new() }
+    fn new(length: usize) -> Guesses {
+        Guesses {
+            vec: Vec::new(),
+            length,
+        }
     }
 
     fn len(&self) -> usize {
@@ -153,67 +364,207 @@ impl Guesses {
     }
 }
 
-fn set_not_in_str(hash: &HashSet<char>, strng: &str) -> bool {
-    let mut successful_match = true;
-    for item in hash {
-        successful_match = successful_match && strng.chars().all(|x| x != *item);
+#[cfg(test)]
+fn letter_count(strng: &str, chr: char) -> u8 {
+    strng.chars().filter(|&c| c == chr).count() as u8
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let no_color_flag = args.iter().any(|arg| arg == "--no-color");
+    set_color_enabled(!no_color_flag && io::stdout().is_terminal());
+
+    let length = parse_flag_value(&args, "--length")
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(5);
+    let word_list_path = parse_flag_value(&args, "--word-list");
+    let words = load_word_list(word_list_path, length);
+    if words.is_empty() {
+        eprintln!(
+            "No {}-letter words available. Pass --word-list <path> with a dictionary containing words of this length.",
+            length
+        );
+        return;
+    }
+    let store = WordStore::build(&words);
+
+    if args.iter().any(|arg| arg == "--benchmark") {
+        let report = benchmark::run_benchmark(&words, &store, length);
+        println!("{}", report);
+        return;
     }
-    successful_match
+
+    let guesses = Guesses::new(length);
+    if length == 5 {
+        println!("Guess any five letter word- \"arose\" is a good choice!");
+    } else {
+        println!("Guess any {}-letter word!", length);
+    }
+    run_guess_loop(guesses, words, &store)
+}
+
+/// Reads the value following `flag` out of the raw argument list, if present.
+fn parse_flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|arg| arg == flag)
+        .and_then(|i| args.get(i + 1))
+        .map(|value| value.as_str())
 }
 
-fn used_at_least_once(hash: &HashSet<char>, strng: &str) -> bool {
-    let mut successful_match = true;
-    for item in hash {
-        successful_match = successful_match && strng.chars().any(|x| x == *item);
+/// Loads the word list to solve against: the user-supplied dictionary at
+/// `path` if given, otherwise the builtin list. Either way the result is
+/// filtered down to words of the requested `length`.
+fn load_word_list(path: Option<&str>, length: usize) -> Vec<&'static str> {
+    match path {
+        Some(path) => load_word_list_from_file(path, length).unwrap_or_else(|err| {
+            eprintln!(
+                "Couldn't load word list from \"{}\" ({}), falling back to the builtin list.",
+                path, err
+            );
+            load_builtin_word_list(length)
+        }),
+        None => load_builtin_word_list(length),
     }
-    successful_match
 }
 
-fn main() {
-    let words = word_list();
-    let guesses = Guesses::new();
-    println!("Guess any five letter word- \"arose\" is a good choice!");
-    run_guess_loop(guesses, words)
+fn load_builtin_word_list(length: usize) -> Vec<&'static str> {
+    word_list()
+        .into_iter()
+        .filter(|word| word.chars().count() == length)
+        .collect()
 }
 
-fn run_guess_loop(mut guesses: Guesses, words: Vec<&str>) {
+/// Reads a custom dictionary from `path`, one word per line, trimming and
+/// lowercasing each entry, dropping any that aren't `length` letters long,
+/// and deduping repeated entries.
+fn load_word_list_from_file(path: &str, length: usize) -> io::Result<Vec<&'static str>> {
+    let contents = std::fs::read_to_string(path)?;
+    let mut seen = HashSet::new();
+    let words = contents
+        .lines()
+        .map(|line| line.trim().to_lowercase())
+        .filter(|word| word.chars().count() == length)
+        .filter(|word| seen.insert(word.clone()))
+        // leaked once at startup so the list can live as long as the builtin one
+        .map(|word| -> &'static str { Box::leak(word.into_boxed_str()) })
+        .collect();
+    Ok(words)
+}
+
+fn run_guess_loop(mut guesses: Guesses, words: Vec<&str>, store: &WordStore) {
     loop {
         get_guess(&mut guesses);
 
-        // I wanted to refactor the below two lines into a function call that
-        // returns flines so that I could write some tests for filter behavior,
-        // but I couldn't figure out a type signature that lets me
-        // return a filtered iterator from a function
-        let agg = AggregateWordResult::from(&guesses.vec);
-        let mut filtered_words = words.iter().filter(|x| agg.word_matches(x));
-        let mut display_words = Vec::new();
-        let mut item_index = 0;
-        while item_index < 10 {
-            let item = filtered_words.next();
-            item_index += 1;
-            if let Some(val) = item {
-                display_words.push(val)
-            }
-        }
-        if item_index == 0 {
+        let agg = AggregateWordResult::from(&guesses.vec, guesses.length);
+        let candidates: Vec<String> = agg.matching_words(store).collect();
+        let candidate_refs: Vec<&str> = candidates.iter().map(String::as_str).collect();
+
+        if candidate_refs.is_empty() {
             println!("There are no words that match the results you entered. Did you make a mistake entering them?");
             break;
-        } else {
-            println!("If the word was correct, press CTRL+C to quit. Otherwise, make a guess with one of the following:");
-            for item in display_words {
-                println!("{}", item);
+        }
+
+        println!(
+            "If the word was correct, press CTRL+C to quit. Otherwise, {} word(s) still match. Top recommended guesses:",
+            candidate_refs.len()
+        );
+        for (word, bits) in best_guesses(&candidate_refs, &words, 5) {
+            println!("{} ({:.2} bits)", word, bits);
+        }
+    }
+}
+
+/// Computes the color pattern that `guess` would receive if `solution` were
+/// the answer, respecting Wordle's duplicate-letter rules: a repeated guess
+/// letter is only marked yellow as many times as it remains unaccounted for
+/// in the solution after greens are resolved.
+pub(crate) fn pattern(guess: &str, solution: &str) -> Vec<GuessedLetterResult> {
+    let guess_chars: Vec<char> = guess.chars().collect();
+    let solution_chars: Vec<char> = solution.chars().collect();
+    let length = guess_chars.len();
+    let mut result = vec![GuessedLetterResult::NotUsed; length];
+    let mut solution_claimed = vec![false; length];
+
+    for i in 0..length {
+        if guess_chars[i] == solution_chars[i] {
+            result[i] = GuessedLetterResult::CorrectSpot;
+            solution_claimed[i] = true;
+        }
+    }
+
+    for i in 0..length {
+        if result[i] == GuessedLetterResult::CorrectSpot {
+            continue;
+        }
+        for j in 0..length {
+            if !solution_claimed[j] && guess_chars[i] == solution_chars[j] {
+                result[i] = GuessedLetterResult::WrongSpot;
+                solution_claimed[j] = true;
+                break;
             }
         }
     }
+
+    result
+}
+
+/// Scores `guess` by the Shannon entropy (in bits) of the pattern it would
+/// produce across `candidates`, the still-possible solutions. A higher score
+/// means the guess splits the candidates into more even, more numerous
+/// groups, which on average narrows the search fastest.
+fn score_guess(guess: &str, candidates: &[&str]) -> f64 {
+    let mut pattern_counts: HashMap<Vec<GuessedLetterResult>, usize> = HashMap::new();
+    for candidate in candidates {
+        *pattern_counts.entry(pattern(guess, candidate)).or_insert(0) += 1;
+    }
+
+    let total = candidates.len() as f64;
+    pattern_counts
+        .values()
+        .map(|&count| {
+            let p = count as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Ranks every word in `guess_pool` by `score_guess` against `candidates` and
+/// returns the top `top_n`, highest entropy first. Once only one candidate
+/// remains there's nothing left to split, so it's returned outright rather
+/// than left to an entropy tie. Ties among the rest favor guesses that are
+/// themselves still-possible solutions, so the solver converges instead of
+/// forever recommending a fixed opener once it already knows the answer.
+pub(crate) fn best_guesses(candidates: &[&str], guess_pool: &[&str], top_n: usize) -> Vec<(String, f64)> {
+    if candidates.len() == 1 {
+        return vec![(candidates[0].to_string(), score_guess(candidates[0], candidates))];
+    }
+
+    let candidate_set: HashSet<&str> = candidates.iter().copied().collect();
+    let mut scored: Vec<(String, f64, bool)> = guess_pool
+        .iter()
+        .map(|guess| {
+            (
+                guess.to_string(),
+                score_guess(guess, candidates),
+                candidate_set.contains(guess),
+            )
+        })
+        .collect();
+    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then(b.2.cmp(&a.2)));
+    scored.truncate(top_n);
+    scored
+        .into_iter()
+        .map(|(word, bits, _)| (word, bits))
+        .collect()
 }
 
 fn get_guess(guesses: &mut Guesses) {
-    let guess_string = prompt_for_guess();
+    let guess_string = prompt_for_guess(guesses.length);
     let guess = prompt_for_results(guess_string);
     guesses.add_guess(guess);
 }
 
-fn prompt_for_guess() -> String {
+fn prompt_for_guess(length: usize) -> String {
     println!("Please enter the word you guessed:");
 
     let mut guess = String::new();
@@ -223,19 +574,37 @@ fn prompt_for_guess() -> String {
             .read_line(&mut guess)
             .expect("Failed to read line");
         guess = guess.trim().to_string(); // remove trailing newline
-        if guess.chars().count() == 5 {
+        if guess.chars().count() == length {
             break;
         }
-        println!("Please enter a five letter word:");
+        println!("Please enter a {}-letter word:", length);
         guess.clear();
     }
     guess
 }
 
 fn prompt_for_results(guess: String) -> GuessedWord {
+    let length = guess.chars().count();
+    let letter_example: String = "gybbg".chars().cycle().take(length).collect();
+    let digit_example: String = "21002".chars().cycle().take(length).collect();
+    println!(
+        "Enter the result as a {length}-character code (\"{letter_example}\" or \"{digit_example}\", g/2=green, y/1=yellow, b/0=black), or press enter to answer letter by letter:",
+    );
+    let mut encoded = String::new();
+    io::stdin()
+        .read_line(&mut encoded)
+        .expect("Failed to read line");
+    let encoded = encoded.trim();
+    if !encoded.is_empty() {
+        match parse_encoded_result(&guess, encoded) {
+            Some(guessed_word) => return guessed_word,
+            None => println!("Couldn't parse that as a result code, answering letter by letter instead:"),
+        }
+    }
+
     let mut letters: Vec<GuessedLetter> = Vec::new();
     'outer: loop {
-        for (_position, letter) in guess.chars().enumerate() {
+        for letter in guess.chars() {
             println!(
                 "Enter the result for the letter \"{}\": [G]reen, [Y]ellow, or [B]lack ",
                 letter
@@ -261,14 +630,39 @@ fn prompt_for_results(guess: String) -> GuessedWord {
                 .expect("Failed to read line");
             let response = input.chars().take(1).last().unwrap();
 
-            if let 'y' = response {
-                break 'outer guessed_word;
+            match response {
+                'y' => break 'outer guessed_word,
+                'n' => {
+                    letters.clear();
+                    continue 'outer;
+                }
+                _ => println!("Please only enter characters 'y' or 'n': "),
             }
-            println!("Please only enter characters 'y' or 'n': ");
         }
     }
 }
 
+/// Parses an encoded result string the same length as `guess` (e.g. "gybbg"
+/// or "21002") into a `GuessedWord`, returning `None` if the length or
+/// characters are invalid so the caller can fall back to interactive
+/// per-letter entry.
+fn parse_encoded_result(guess: &str, encoded: &str) -> Option<GuessedWord> {
+    if encoded.chars().count() != guess.chars().count() {
+        return None;
+    }
+    let mut letters = Vec::with_capacity(guess.chars().count());
+    for (letter, code) in guess.chars().zip(encoded.chars()) {
+        let result = match code.to_ascii_lowercase() {
+            'g' | '2' => GuessedLetterResult::CorrectSpot,
+            'y' | '1' => GuessedLetterResult::WrongSpot,
+            'b' | '0' => GuessedLetterResult::NotUsed,
+            _ => return None,
+        };
+        letters.push(GuessedLetter { letter, result });
+    }
+    Some(GuessedWord { letters })
+}
+
 fn prompt_for_color() -> GuessedLetterResult {
     loop {
         let mut input = String::new();
@@ -292,7 +686,7 @@ mod tests {
 
     fn filtered_words_contains_entry(guesses: &Guesses, entry: &str) -> bool {
         let words = word_list();
-        let agg = AggregateWordResult::from(&guesses.vec);
+        let agg = AggregateWordResult::from(&guesses.vec, 5);
         let mut filtered_words = words.iter().filter(|x| agg.word_matches(x));
         filtered_words.any(|&word| word == entry)
     }
@@ -324,10 +718,206 @@ mod tests {
         let guessed_word = GuessedWord {
             letters: guess_cargo,
         };
-        let mut guess = Guesses::new();
+        let mut guess = Guesses::new(5);
         guess.add_guess(guessed_word);
 
         assert!(filtered_words_contains_entry(&guess, "racer"));
         assert!(!filtered_words_contains_entry(&guess, "zebra"));
     }
+
+    #[test]
+    fn duplicate_letter_does_not_ban_solutions_with_one_occurrence() {
+        // "eerie" guessed against a solution with exactly one 'e' (e.g.
+        // "crepe" style): first E green, second E black. The black E must
+        // not ban 'e' globally, only cap it at one occurrence.
+        let e1 = GuessedLetter {
+            letter: 'e',
+            result: GuessedLetterResult::CorrectSpot,
+        };
+        let e2 = GuessedLetter {
+            letter: 'e',
+            result: GuessedLetterResult::NotUsed,
+        };
+        let r = GuessedLetter {
+            letter: 'r',
+            result: GuessedLetterResult::NotUsed,
+        };
+        let i = GuessedLetter {
+            letter: 'i',
+            result: GuessedLetterResult::NotUsed,
+        };
+        let e3 = GuessedLetter {
+            letter: 'e',
+            result: GuessedLetterResult::NotUsed,
+        };
+        let guessed_word = GuessedWord {
+            letters: vec![e1, e2, r, i, e3],
+        };
+        let mut guess = Guesses::new(5);
+        guess.add_guess(guessed_word);
+
+        let agg = AggregateWordResult::from(&guess.vec, 5);
+        assert!(agg.word_matches("epoch"));
+        assert!(!agg.word_matches("emcee"));
+    }
+
+    #[test]
+    fn parse_encoded_result_accepts_letter_and_digit_forms() {
+        let letters = parse_encoded_result("cargo", "gybbg").unwrap();
+        assert_eq!(letters.letters[0].result, GuessedLetterResult::CorrectSpot);
+        assert_eq!(letters.letters[1].result, GuessedLetterResult::WrongSpot);
+        assert_eq!(letters.letters[2].result, GuessedLetterResult::NotUsed);
+
+        let digits = parse_encoded_result("cargo", "21002").unwrap();
+        assert_eq!(digits.letters[0].result, GuessedLetterResult::CorrectSpot);
+        assert_eq!(digits.letters[1].result, GuessedLetterResult::WrongSpot);
+        assert_eq!(digits.letters[2].result, GuessedLetterResult::NotUsed);
+    }
+
+    #[test]
+    fn parse_encoded_result_rejects_malformed_input() {
+        assert!(parse_encoded_result("cargo", "gyb").is_none());
+        assert!(parse_encoded_result("cargo", "gybbz").is_none());
+    }
+
+    #[test]
+    fn pattern_handles_duplicate_letters() {
+        let result = pattern("eerie", "melee");
+        assert_eq!(result[0], GuessedLetterResult::WrongSpot);
+        assert_eq!(result[1], GuessedLetterResult::CorrectSpot);
+        assert_eq!(result[2], GuessedLetterResult::NotUsed);
+        assert_eq!(result[3], GuessedLetterResult::NotUsed);
+        assert_eq!(result[4], GuessedLetterResult::CorrectSpot);
+    }
+
+    #[test]
+    fn score_guess_prefers_more_even_splits() {
+        let candidates = vec!["arose", "racer", "cargo"];
+        // "arose" produces a distinct pattern against every candidate, while
+        // "might" can't tell "arose" and "racer" apart, so it scores lower.
+        let distinguishing_score = score_guess("arose", &candidates);
+        let ambiguous_score = score_guess("might", &candidates);
+        assert!(distinguishing_score > ambiguous_score);
+    }
+
+    #[test]
+    fn best_guesses_returns_the_lone_candidate_once_narrowed_down() {
+        let guess_pool = vec!["arose", "racer", "cargo"];
+        let top = best_guesses(&["racer"], &guess_pool, 5);
+        assert_eq!(top[0].0, "racer");
+        assert_eq!(top.len(), 1);
+    }
+
+    #[test]
+    fn best_guesses_breaks_entropy_ties_toward_remaining_candidates() {
+        // "tacet" and "racer" split this candidate set identically (every
+        // candidate gets its own all-black/all-green pattern), so without a
+        // tie-break the first word in `guess_pool` would always win even
+        // though "racer" is itself still a possible solution.
+        let candidates = vec!["racer", "mocha"];
+        let guess_pool = vec!["tacet", "racer", "mocha"];
+        let top = best_guesses(&candidates, &guess_pool, 1);
+        assert_eq!(top[0].0, "racer");
+    }
+
+    #[test]
+    fn matching_words_agrees_with_word_matches() {
+        let e1 = GuessedLetter {
+            letter: 'e',
+            result: GuessedLetterResult::CorrectSpot,
+        };
+        let e2 = GuessedLetter {
+            letter: 'e',
+            result: GuessedLetterResult::NotUsed,
+        };
+        let r = GuessedLetter {
+            letter: 'r',
+            result: GuessedLetterResult::NotUsed,
+        };
+        let i = GuessedLetter {
+            letter: 'i',
+            result: GuessedLetterResult::NotUsed,
+        };
+        let e3 = GuessedLetter {
+            letter: 'e',
+            result: GuessedLetterResult::NotUsed,
+        };
+        let guessed_word = GuessedWord {
+            letters: vec![e1, e2, r, i, e3],
+        };
+        let mut guess = Guesses::new(5);
+        guess.add_guess(guessed_word);
+
+        let agg = AggregateWordResult::from(&guess.vec, 5);
+        let words = word_list();
+        let store = WordStore::build(&words);
+        let mut from_store: Vec<String> = agg.matching_words(&store).collect();
+        from_store.sort();
+        let mut from_scan: Vec<String> = words
+            .iter()
+            .filter(|w| agg.word_matches(w))
+            .map(|w| w.to_string())
+            .collect();
+        from_scan.sort();
+        assert_eq!(from_store, from_scan);
+        assert!(!from_store.is_empty());
+    }
+
+    #[test]
+    fn matching_words_handles_multi_byte_characters() {
+        let words = vec!["h\u{e9}ros", "zebra", "fache"];
+        let store = WordStore::build(&words);
+        let agg = AggregateWordResult::from(&[], 5);
+        let mut matched: Vec<String> = agg.matching_words(&store).collect();
+        matched.sort();
+        assert_eq!(matched, vec!["fache", "h\u{e9}ros", "zebra"]);
+    }
+
+    #[test]
+    fn aggregate_word_result_matches_at_a_non_standard_length() {
+        // "barnacle" (8 letters) guessed all-black: every one of its letters
+        // is banned, but an 8-letter word that avoids them should still match.
+        let letters: Vec<GuessedLetter> = "barnacle"
+            .chars()
+            .map(|letter| GuessedLetter {
+                letter,
+                result: GuessedLetterResult::NotUsed,
+            })
+            .collect();
+        let mut guess = Guesses::new(8);
+        guess.add_guess(GuessedWord { letters });
+
+        let agg = AggregateWordResult::from(&guess.vec, 8);
+        assert!(agg.word_matches("dogfight"));
+        assert!(!agg.word_matches("scramble"));
+    }
+
+    #[test]
+    fn load_word_list_from_file_trims_lowercases_and_filters_by_length() {
+        let path = std::env::temp_dir().join(format!(
+            "wordle-aid-test-word-list-{:?}",
+            std::thread::current().id()
+        ));
+        std::fs::write(&path, "  Racer\nTOO\nCARGO\nracer\n\nma\u{f1}ana\n").unwrap();
+
+        let words = load_word_list_from_file(path.to_str().unwrap(), 5).unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(words, vec!["racer", "cargo"]);
+    }
+
+    #[test]
+    fn load_word_list_falls_back_to_builtin_list_when_file_is_missing() {
+        let words = load_word_list(Some("/nonexistent/wordle-aid-word-list.txt"), 5);
+        assert_eq!(words, load_builtin_word_list(5));
+    }
+
+    #[test]
+    fn load_builtin_word_list_is_empty_for_a_non_standard_length() {
+        // The builtin WORD_LIST only contains 5-letter words; other lengths
+        // need a --word-list to have anything to solve against.
+        assert!(load_builtin_word_list(4).is_empty());
+        assert!(load_word_list(None, 4).is_empty());
+    }
 }
@@ -0,0 +1,33 @@
+use fst::{Automaton, IntoStreamer, Set, Streamer};
+
+/// A word list indexed as a finite-state transducer so that constrained
+/// lookups only traverse the prefixes that can still match, instead of
+/// scanning every word on every round.
+pub(crate) struct WordStore {
+    set: Set<Vec<u8>>,
+}
+
+impl WordStore {
+    pub(crate) fn build(words: &[&str]) -> WordStore {
+        let mut sorted: Vec<&str> = words.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        let set =
+            Set::from_iter(sorted).expect("word list must be sorted and free of duplicates");
+        WordStore { set }
+    }
+
+    /// Streams every word matching `automaton` out of the FST, pruning the
+    /// traversal at the first byte that can no longer match rather than
+    /// materializing and checking every word in the store.
+    pub(crate) fn matching<A: Automaton>(&self, automaton: A) -> Vec<String> {
+        let mut stream = self.set.search(automaton).into_stream();
+        let mut results = Vec::new();
+        while let Some(key) = stream.next() {
+            if let Ok(word) = String::from_utf8(key.to_vec()) {
+                results.push(word);
+            }
+        }
+        results
+    }
+}
@@ -0,0 +1,819 @@
+const WORD_LIST: &str = "\
+arose
+racer
+cargo
+zebra
+adieu
+audio
+stare
+irate
+crane
+slate
+trace
+crate
+plate
+grate
+spare
+share
+shore
+store
+stone
+shine
+swine
+spine
+chime
+crime
+grime
+prime
+trope
+grope
+scope
+slope
+stale
+scale
+smile
+while
+white
+write
+wrote
+grove
+prove
+drove
+drive
+crave
+brave
+grave
+shave
+shape
+snipe
+spice
+slice
+slide
+glide
+guide
+quite
+quiet
+title
+tithe
+these
+theme
+thorn
+shorn
+storm
+sworn
+sword
+score
+chord
+chore
+whore
+where
+there
+their
+theft
+thief
+third
+shirt
+short
+sport
+sprat
+spray
+stray
+strap
+strip
+strut
+stuns
+stung
+swung
+sling
+sting
+swing
+thing
+think
+thank
+tanks
+flank
+plank
+blank
+blink
+brink
+drink
+click
+trick
+truck
+track
+crack
+black
+slack
+stack
+smack
+snack
+quack
+quark
+spark
+shark
+charm
+alarm
+array
+arise
+aside
+asset
+audit
+bacon
+badge
+baker
+basic
+basin
+basis
+beach
+beard
+beast
+begin
+being
+belly
+below
+bench
+berry
+birth
+blade
+blame
+blast
+blend
+bless
+blind
+block
+blood
+bloom
+blown
+blues
+bluff
+blunt
+board
+boast
+boost
+booth
+bound
+brain
+brand
+brass
+bread
+break
+breed
+brick
+bride
+brief
+bring
+broad
+broke
+brook
+broom
+brown
+brush
+build
+built
+bunch
+burst
+cabin
+cable
+candy
+canon
+carry
+catch
+cause
+chain
+chair
+chalk
+champ
+chaos
+chart
+chase
+cheap
+check
+cheek
+cheer
+chess
+chest
+chick
+chief
+child
+chill
+china
+choir
+chose
+civic
+civil
+claim
+class
+clean
+clear
+clerk
+cliff
+climb
+cling
+cloak
+clock
+close
+cloth
+cloud
+clown
+clubs
+coach
+coast
+could
+count
+court
+cover
+craft
+crash
+crawl
+crazy
+cream
+creek
+creep
+crest
+crisp
+cross
+crowd
+crown
+crude
+cruel
+crush
+curve
+cycle
+daily
+dairy
+dance
+dated
+death
+debut
+decay
+delay
+delta
+dense
+depth
+diary
+digit
+diner
+dirty
+disco
+ditch
+diver
+dodge
+doing
+donor
+doubt
+dough
+dozen
+draft
+drain
+drama
+drank
+dream
+dress
+dried
+drift
+drill
+drone
+drown
+drums
+dryer
+ebony
+edify
+eight
+elbow
+elder
+elect
+elite
+email
+empty
+enact
+ended
+enjoy
+enter
+entry
+equal
+equip
+erase
+error
+essay
+event
+every
+exact
+exile
+exist
+extra
+fable
+faith
+false
+fancy
+fatal
+feast
+fence
+ferry
+fever
+fiber
+field
+fiery
+fifth
+fifty
+fight
+filed
+films
+final
+first
+flame
+flash
+fleet
+flesh
+flick
+flint
+float
+flock
+flood
+floor
+flour
+fluid
+flush
+focal
+focus
+force
+forge
+forth
+forty
+forum
+found
+frame
+frank
+fraud
+fresh
+front
+frost
+fruit
+fully
+funky
+fuzzy
+gamer
+gauge
+genre
+ghost
+giant
+given
+giver
+glade
+glass
+gleam
+globe
+glory
+glove
+going
+grace
+grade
+grain
+grand
+grant
+grape
+graph
+grasp
+grass
+gravy
+great
+greed
+green
+greet
+grief
+grill
+grind
+groom
+gross
+group
+grown
+guard
+guess
+guest
+habit
+handy
+happy
+harsh
+haste
+haven
+heart
+heavy
+hedge
+hello
+hence
+herbs
+hobby
+holly
+honey
+honor
+horse
+hotel
+house
+human
+humid
+hurry
+ideal
+image
+imply
+index
+inner
+input
+irony
+issue
+ivory
+jelly
+joint
+judge
+juice
+keeps
+kiosk
+knife
+knock
+known
+label
+labor
+large
+laser
+later
+laugh
+layer
+learn
+lease
+least
+leave
+ledge
+legal
+lemon
+level
+light
+limit
+linen
+liner
+lives
+lobby
+local
+lodge
+logic
+loose
+lover
+lower
+loyal
+lucky
+lunar
+lunch
+lying
+magic
+major
+maker
+mango
+march
+marsh
+match
+maybe
+mayor
+meant
+medal
+media
+melon
+mercy
+merge
+merit
+metal
+meter
+might
+minor
+minus
+mixed
+model
+money
+month
+moral
+motor
+mount
+mouse
+mouth
+mover
+movie
+music
+naive
+naked
+nasty
+naval
+needy
+nerve
+never
+newly
+niece
+night
+noble
+noise
+north
+notch
+novel
+nurse
+nylon
+oasis
+occur
+ocean
+offer
+often
+olive
+onion
+opera
+orbit
+order
+organ
+other
+otter
+ought
+outer
+owner
+oxide
+paint
+panel
+panic
+paper
+party
+pasta
+patch
+pause
+peace
+pearl
+phase
+phone
+photo
+piano
+piece
+pilot
+pinch
+pitch
+pixel
+pizza
+place
+plain
+plane
+plant
+plaza
+point
+poker
+polar
+porch
+pound
+power
+press
+price
+pride
+print
+prior
+prize
+proof
+proud
+pulse
+punch
+pupil
+puppy
+query
+queue
+quick
+quilt
+quirk
+quota
+quote
+radio
+raise
+rally
+ranch
+range
+rapid
+ratio
+reach
+ready
+realm
+rebel
+refer
+reign
+relax
+reply
+reset
+resin
+rider
+ridge
+rifle
+right
+rigid
+rival
+river
+roast
+robin
+robot
+rocky
+rogue
+roman
+roost
+rough
+round
+route
+royal
+rugby
+ruler
+rural
+salad
+sauce
+scarf
+scene
+scent
+scoop
+scout
+scrap
+screw
+sense
+serve
+seven
+shade
+shaft
+shake
+shall
+shame
+sharp
+sheep
+sheet
+shelf
+shell
+shift
+shock
+shoot
+shown
+shrug
+siege
+sight
+silly
+since
+sixth
+sixty
+sized
+skill
+skull
+slash
+sleek
+sleep
+small
+smart
+smash
+smell
+smoke
+snake
+solar
+solid
+solve
+sonic
+sorry
+sound
+south
+space
+speak
+speed
+spell
+spend
+spike
+spoil
+spoke
+squad
+staff
+stage
+stain
+stair
+stake
+stall
+stamp
+stand
+start
+state
+steal
+steam
+steel
+steep
+steer
+stick
+stiff
+still
+stock
+stood
+stool
+story
+stove
+straw
+study
+stuff
+style
+sugar
+suite
+sunny
+super
+surge
+swarm
+swear
+sweat
+sweep
+sweet
+swift
+table
+taken
+taste
+teach
+tease
+teeth
+tempo
+tense
+tenth
+thick
+those
+three
+throw
+thumb
+tiger
+tight
+timer
+titan
+tonic
+topic
+torch
+total
+touch
+tough
+tower
+toxic
+trade
+trail
+train
+trait
+trash
+treat
+trend
+trial
+tribe
+troop
+truly
+trunk
+trust
+truth
+tulip
+tumor
+tutor
+twice
+twist
+ultra
+uncle
+under
+union
+unity
+until
+upper
+upset
+urban
+usage
+usual
+utter
+vague
+valid
+valve
+vapor
+vault
+venue
+verse
+video
+vigor
+villa
+vinyl
+viral
+virus
+visit
+vital
+vivid
+vocal
+vowel
+wagon
+waste
+watch
+water
+weigh
+weird
+which
+whole
+whose
+woman
+world
+worry
+worth
+wound
+woven
+wreck
+wrist
+wrong
+yield
+young
+yummy
+zesty
+";
+
+pub fn word_list() -> Vec<&'static str> {
+    WORD_LIST.lines().filter(|w| !w.is_empty()).collect()
+}